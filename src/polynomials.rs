@@ -1,3 +1,4 @@
+use num_complex::Complex;
 use std::{fmt, ops};
 
 #[derive(Debug, PartialEq)]
@@ -5,6 +6,35 @@ pub struct Polynom {
     coeficients: Vec<f64>,
 }
 
+/// Degree sum above which [`ops::Mul`] switches from the schoolbook double loop
+/// to the FFT-based convolution.
+const FFT_THRESHOLD: usize = 64;
+
+/// Tolerance used by [`Polynom::gcd`] to decide when a remainder has vanished.
+/// It sits comfortably above f64 machine epsilon so that floating-point noise
+/// accumulated by the division does not inflate the remainder's degree.
+const GCD_TOLERANCE: f64 = 1e-9;
+
+/// Convergence tolerance for [`Polynom::roots`]. The simultaneous Aberth update
+/// cannot drive the correction down to `crate::EPSILON` in f64, so a reachable
+/// absolute threshold is used to stop once the guesses have settled.
+const ROOT_TOLERANCE: f64 = 1e-13;
+
+/// Relative tolerance used by the FFT multiplication path to snap a recovered
+/// coefficient to the nearest integer. FFT round-trip residues sit around
+/// `1e-13`, far above this is comfortable, so integer products come back
+/// exactly and agree with the schoolbook loop under `PartialEq`.
+const FFT_ROUND_TOLERANCE: f64 = 1e-6;
+
+/// Selects the strategy used by [`Polynom::find_roots`].
+#[derive(Debug, PartialEq)]
+pub enum RootMethod {
+    /// The iterative Aberth–Ehrlich method (see [`Polynom::roots`]).
+    Aberth,
+    /// The eigenvalues of the companion matrix (see [`Polynom::companion_roots`]).
+    Companion,
+}
+
 impl Polynom {
     pub fn zero() -> Self {
         Polynom {
@@ -19,7 +49,7 @@ impl Polynom {
     }
 
     pub fn initialize(coefs: Vec<f64>) -> Self {
-        assert!(coefs.len() > 0);
+        assert!(!coefs.is_empty());
         assert_ne!(*coefs.last().unwrap(), 0.0);
         Polynom { coeficients: coefs }
     }
@@ -58,6 +88,312 @@ impl Polynom {
         }
         self
     }
+
+    /**
+    divides self by `divisor` using Euclidean long division and returns the
+    pair `(quotient, remainder)` such that `self = quotient * divisor + remainder`
+    with `deg(remainder) < deg(divisor)`.
+
+    At each step the leading coefficient of the current remainder is divided by
+    the leading coefficient of the divisor to form the next quotient term at
+    degree `deg(rem) - deg(div)`; `term * divisor` is subtracted from the
+    remainder until its degree drops below the divisor's. Panics on a zero
+    divisor.
+    */
+    pub fn div_rem(self, divisor: &Polynom) -> (Polynom, Polynom) {
+        assert!(
+            divisor.degree() > 0 || divisor.coeficients[0] != 0.0,
+            "division by the zero polynomial"
+        );
+
+        let ddeg = divisor.degree();
+
+        // nothing to divide: the remainder is self unchanged
+        if self.degree() < ddeg {
+            return (Polynom::zero(), self.trim());
+        }
+
+        let dlead = divisor.coeficients[ddeg];
+        let mut rem = self.coeficients;
+        let mut quot = vec![0.0; rem.len() - ddeg];
+
+        loop {
+            while rem.len() > 1 && *rem.last().unwrap() == 0.0 {
+                rem.pop();
+            }
+            let rdeg = rem.len() - 1;
+            if rdeg < ddeg || (rdeg == 0 && rem[0] == 0.0) {
+                break;
+            }
+            let term_deg = rdeg - ddeg;
+            let coef = rem[rdeg] / dlead;
+            quot[term_deg] = coef;
+            for j in 0..=ddeg {
+                rem[term_deg + j] -= coef * divisor.coeficients[j];
+            }
+            rem[rdeg] = 0.0;
+        }
+
+        (
+            Polynom { coeficients: quot }.trim(),
+            Polynom { coeficients: rem }.trim(),
+        )
+    }
+
+    /**
+    computes the greatest common divisor of `self` and `other` with the
+    Euclidean algorithm, repeatedly replacing `(a, b)` with `(b, a % b)` until
+    the remainder vanishes, then returns the last nonzero polynomial in monic
+    form.
+
+    Because the coefficients are floating point, each remainder is rounded to
+    zero within `GCD_TOLERANCE` before its degree is examined, so coefficients
+    that are merely numerical noise are treated as zero and cannot spin the loop
+    forever.
+    */
+    pub fn gcd(self, other: Polynom) -> Polynom {
+        let mut a = self;
+        let mut b = other;
+        while !b.is_negligible() {
+            let r = a.div_rem(&b).1.round_to_zero(GCD_TOLERANCE);
+            a = b;
+            b = r;
+        }
+        a.monic_form()
+    }
+
+    /// returns true when every coefficient is below `GCD_TOLERANCE` in
+    /// magnitude, i.e. the polynomial is the zero polynomial up to tolerance.
+    fn is_negligible(&self) -> bool {
+        self.coeficients.iter().all(|c| c.abs() < GCD_TOLERANCE)
+    }
+
+    /// divides every coefficient by the leading one so the result is monic.
+    fn monic_form(self) -> Polynom {
+        let lead = self.coeficients[self.degree()];
+        self.by(1.0 / lead)
+    }
+
+    /// the `l1` norm: the sum of the coefficient magnitudes.
+    pub fn l1_norm(&self) -> f64 {
+        self.coeficients.iter().map(|c| c.abs()).sum()
+    }
+
+    /// the `l2` norm: the square root of the sum of the squared coefficients.
+    pub fn l2_norm(&self) -> f64 {
+        self.coeficients.iter().map(|c| c * c).sum::<f64>().sqrt()
+    }
+
+    /// the `l∞` norm: the largest coefficient magnitude.
+    pub fn linf_norm(&self) -> f64 {
+        self.coeficients.iter().map(|c| c.abs()).fold(0.0, f64::max)
+    }
+
+    /// divides through by the leading coefficient, returning the monic form.
+    pub fn monic(self) -> Polynom {
+        self.monic_form()
+    }
+
+    /// zeroes every coefficient whose magnitude is below `tol`, then re-trims
+    /// trailing zeros to obtain a canonical form up to tolerance.
+    pub fn round_to_zero(mut self, tol: f64) -> Polynom {
+        for c in self.coeficients.iter_mut() {
+            if c.abs() < tol {
+                *c = 0.0;
+            }
+        }
+        self.trim()
+    }
+
+    /**
+    multiplies two polynomials through their point-value representation: both
+    operands are transformed with a complex FFT, multiplied pointwise, and the
+    coefficients are recovered with an inverse FFT. Each recovered coefficient
+    is snapped to the nearest integer when it lies within `FFT_ROUND_TOLERANCE`
+    (relative to its magnitude), so the round-trip noise is removed and integer
+    products match the schoolbook loop exactly. This runs in O(n log n) and
+    backs [`ops::Mul`] for high-degree operands.
+    */
+    fn fft_mul(&self, rhs: &Polynom) -> Polynom {
+        let result_len = self.coeficients.len() + rhs.coeficients.len() - 1;
+        let mut size = 1;
+        while size < result_len {
+            size <<= 1;
+        }
+
+        let mut fa: Vec<Complex<f64>> =
+            self.coeficients.iter().map(|&c| Complex::new(c, 0.0)).collect();
+        let mut fb: Vec<Complex<f64>> =
+            rhs.coeficients.iter().map(|&c| Complex::new(c, 0.0)).collect();
+        fa.resize(size, Complex::new(0.0, 0.0));
+        fb.resize(size, Complex::new(0.0, 0.0));
+
+        fft(&mut fa, false);
+        fft(&mut fb, false);
+        for i in 0..size {
+            fa[i] *= fb[i];
+        }
+        fft(&mut fa, true);
+
+        let coefs = (0..result_len)
+            .map(|i| {
+                let re = fa[i].re;
+                let nearest = re.round();
+                if (re - nearest).abs() <= FFT_ROUND_TOLERANCE * nearest.abs().max(1.0) {
+                    nearest
+                } else {
+                    re
+                }
+            })
+            .collect();
+        Polynom { coeficients: coefs }.trim()
+    }
+
+    /**
+    evaluates the polynomial at the real point `x` with Horner's rule, folding
+    the coefficients high-to-low as `acc = acc * x + a_i`.
+    */
+    pub fn eval(&self, x: f64) -> f64 {
+        let mut acc = 0.0;
+        for i in (0..=self.degree()).rev() {
+            acc = acc * x + self.coeficients[i];
+        }
+        acc
+    }
+
+    /**
+    returns all complex roots using the requested [`RootMethod`]: either the
+    iterative Aberth–Ehrlich sweep or the eigenvalues of the companion matrix.
+    */
+    pub fn find_roots(&self, method: RootMethod) -> Vec<Complex<f64>> {
+        match method {
+            RootMethod::Aberth => self.roots(),
+            RootMethod::Companion => self.companion_roots(),
+        }
+    }
+
+    /**
+    finds the roots as the eigenvalues of the companion matrix of the monic
+    form of the polynomial. The `n × n` companion matrix carries ones on the
+    subdiagonal and `-a_i / a_n` down its last column; the eigenvalues are then
+    extracted with the double-shift QR algorithm.
+    */
+    pub fn companion_roots(&self) -> Vec<Complex<f64>> {
+        let n = self.degree();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let lead = self.coeficients[n];
+        let mut matrix = vec![vec![0.0; n]; n];
+        for i in 1..n {
+            matrix[i][i - 1] = 1.0;
+        }
+        for (row, &coef) in matrix.iter_mut().zip(self.coeficients.iter()) {
+            row[n - 1] = -coef / lead;
+        }
+
+        hessenberg_eigenvalues(matrix)
+    }
+
+    /**
+    returns the derivative, whose coefficients are `a_i * i` shifted down one
+    degree. The derivative of a constant is the zero polynomial.
+    */
+    pub fn derivative(&self) -> Polynom {
+        if self.degree() == 0 {
+            return Polynom::zero();
+        }
+        let coefs = (1..=self.degree())
+            .map(|i| self.coeficients[i] * (i as f64))
+            .collect();
+        Polynom { coeficients: coefs }.trim()
+    }
+
+    /**
+    returns the indefinite integral, whose coefficients are `a_i / (i + 1)`
+    shifted up one degree, with `constant` placed at degree 0.
+    */
+    pub fn integral(&self, constant: f64) -> Polynom {
+        let mut coefs = vec![constant];
+        for i in 0..=self.degree() {
+            coefs.push(self.coeficients[i] / ((i as f64) + 1.0));
+        }
+        Polynom { coeficients: coefs }.trim()
+    }
+
+    /**
+    evaluates the polynomial and its derivative at the complex point `x` in a
+    single Horner sweep, returning `(p(x), p'(x))`.
+    */
+    fn eval_complex(&self, x: Complex<f64>) -> (Complex<f64>, Complex<f64>) {
+        let mut val = Complex::new(0.0, 0.0);
+        let mut der = Complex::new(0.0, 0.0);
+        for i in (0..=self.degree()).rev() {
+            der = der * x + val;
+            val = val * x + Complex::new(self.coeficients[i], 0.0);
+        }
+        (val, der)
+    }
+
+    /**
+    returns all `degree()` complex roots at once with the Aberth–Ehrlich
+    iteration, which converges cubically.
+
+    The initial guesses are spread on a circle of radius `1 + max_i |a_i / a_n|`
+    (the Cauchy bound) at angles `2πk/n` with a small constant offset so that no
+    two coincide. Each iteration computes, for every guess, the Newton term
+    `w_k = p(z_k)/p'(z_k)` and the Aberth correction
+    `w_k / (1 - w_k * Σ_{j≠k} 1/(z_k - z_j))`, then subtracts it. The loop stops
+    once the largest correction falls below `ROOT_TOLERANCE` or after
+    `crate::MAX_ITERATIONS`.
+    */
+    pub fn roots(&self) -> Vec<Complex<f64>> {
+        let n = self.degree();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let lead = self.coeficients[n];
+        let radius = 1.0
+            + (0..n)
+                .map(|i| (self.coeficients[i] / lead).abs())
+                .fold(0.0, f64::max);
+
+        let offset = 0.45;
+        let mut z: Vec<Complex<f64>> = (0..n)
+            .map(|k| {
+                let theta =
+                    2.0 * std::f64::consts::PI * (k as f64) / (n as f64) + offset;
+                Complex::new(radius * theta.cos(), radius * theta.sin())
+            })
+            .collect();
+
+        let one = Complex::new(1.0, 0.0);
+        let mut iteration = 0u32;
+        loop {
+            let current = z.clone();
+            let mut max_offset = 0.0_f64;
+            for k in 0..n {
+                let (p, dp) = self.eval_complex(current[k]);
+                let w = p / dp;
+                let mut sum = Complex::new(0.0, 0.0);
+                for j in 0..n {
+                    if j != k {
+                        sum += one / (current[k] - current[j]);
+                    }
+                }
+                let correction = w / (one - w * sum);
+                z[k] = current[k] - correction;
+                max_offset = max_offset.max(correction.norm());
+            }
+            iteration += 1;
+            if max_offset < ROOT_TOLERANCE || iteration >= crate::MAX_ITERATIONS {
+                break;
+            }
+        }
+        z
+    }
 }
 
 impl fmt::Display for Polynom {
@@ -84,7 +420,7 @@ impl fmt::Display for Polynom {
                 }
             }
         }
-        if res.len() == 0 {
+        if res.is_empty() {
             res.push('0');
         }
         write!(f, "{}", res)
@@ -119,6 +455,9 @@ impl ops::Mul<Polynom> for Polynom {
 
     fn mul(self, rhs: Polynom) -> Self::Output {
         let d = self.degree() + rhs.degree();
+        if d > FFT_THRESHOLD {
+            return self.fft_mul(&rhs);
+        }
         let mut result = Polynom::single(d);
         result.coeficients[d] = 0.0;
         for i in 0..=self.degree() {
@@ -130,6 +469,281 @@ impl ops::Mul<Polynom> for Polynom {
     }
 }
 
+/**
+in-place iterative radix-2 Cooley–Tukey FFT on `a`, whose length must be a
+power of two. `invert` selects the inverse transform, which also divides the
+result by the length.
+*/
+fn fft(a: &mut [Complex<f64>], invert: bool) {
+    let n = a.len();
+
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j ^= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let ang = 2.0 * std::f64::consts::PI / (len as f64)
+            * if invert { -1.0 } else { 1.0 };
+        let wlen = Complex::new(ang.cos(), ang.sin());
+        let mut i = 0;
+        while i < n {
+            let mut w = Complex::new(1.0, 0.0);
+            for k in 0..len / 2 {
+                let u = a[i + k];
+                let v = a[i + k + len / 2] * w;
+                a[i + k] = u + v;
+                a[i + k + len / 2] = u - v;
+                w *= wlen;
+            }
+            i += len;
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        for x in a.iter_mut() {
+            *x /= n as f64;
+        }
+    }
+}
+
+/// `|a|` carrying the sign of `b` (with `+` for `b == 0`), as in the classic
+/// numerical linear algebra `SIGN` helper.
+fn sign(a: f64, b: f64) -> f64 {
+    if b >= 0.0 {
+        a.abs()
+    } else {
+        -a.abs()
+    }
+}
+
+/**
+computes the eigenvalues of a real upper Hessenberg matrix with the double-shift
+QR algorithm, returning them as complex numbers (real eigenvalues have zero
+imaginary part, complex ones appear in conjugate pairs).
+
+The matrix is taken by value and consumed. The work is done on a one-based
+padded copy, so the index arithmetic mirrors the reference algorithm.
+*/
+fn hessenberg_eigenvalues(matrix: Vec<Vec<f64>>) -> Vec<Complex<f64>> {
+    let n = matrix.len();
+    let mut a = vec![vec![0.0f64; n + 1]; n + 1];
+    for i in 0..n {
+        for j in 0..n {
+            a[i + 1][j + 1] = matrix[i][j];
+        }
+    }
+
+    let mut wr = vec![0.0f64; n + 1];
+    let mut wi = vec![0.0f64; n + 1];
+
+    let mut anorm = 0.0;
+    for (i, row) in a.iter().enumerate().skip(1) {
+        for &val in row.iter().skip(i.saturating_sub(1).max(1)) {
+            anorm += val.abs();
+        }
+    }
+
+    let mut nn = n as isize;
+    let mut t = 0.0;
+    while nn >= 1 {
+        let mut its = 0;
+        loop {
+            // search for a small subdiagonal element to split off a block
+            let mut l = nn;
+            while l >= 2 {
+                let li = l as usize;
+                let mut s = a[li - 1][li - 1].abs() + a[li][li].abs();
+                if s == 0.0 {
+                    s = anorm;
+                }
+                if a[li][li - 1].abs() + s == s {
+                    a[li][li - 1] = 0.0;
+                    break;
+                }
+                l -= 1;
+            }
+
+            let nu = nn as usize;
+            let mut x = a[nu][nu];
+            if l == nn {
+                // one real root
+                wr[nu] = x + t;
+                wi[nu] = 0.0;
+                nn -= 1;
+                break;
+            }
+
+            let mut y = a[nu - 1][nu - 1];
+            let mut w = a[nu][nu - 1] * a[nu - 1][nu];
+            if l == nn - 1 {
+                // a real pair or a complex conjugate pair
+                let p = 0.5 * (y - x);
+                let q = p * p + w;
+                let mut z = q.abs().sqrt();
+                x += t;
+                if q >= 0.0 {
+                    z = p + sign(z, p);
+                    wr[nu - 1] = x + z;
+                    wr[nu] = if z != 0.0 { x - w / z } else { x + z };
+                    wi[nu - 1] = 0.0;
+                    wi[nu] = 0.0;
+                } else {
+                    wr[nu - 1] = x + p;
+                    wr[nu] = x + p;
+                    wi[nu - 1] = -z;
+                    wi[nu] = z;
+                }
+                nn -= 2;
+                break;
+            }
+
+            // no convergence yet: apply an exceptional shift occasionally, and
+            // give up gracefully after many iterations
+            if its == 100 {
+                wr[nu] = x + t;
+                wi[nu] = 0.0;
+                nn -= 1;
+                break;
+            }
+            if its == 10 || its == 20 {
+                t += x;
+                for (i, row) in a.iter_mut().enumerate().take(nu + 1).skip(1) {
+                    row[i] -= x;
+                }
+                let s = a[nu][nu - 1].abs() + a[nu - 1][nu - 2].abs();
+                x = 0.75 * s;
+                y = x;
+                w = -0.4375 * s * s;
+            }
+            its += 1;
+
+            // look for two consecutive small subdiagonal elements
+            let mut p = 0.0;
+            let mut q = 0.0;
+            let mut r = 0.0;
+            let mut m = nn - 2;
+            while m >= l {
+                let mu = m as usize;
+                let z = a[mu][mu];
+                let rr = x - z;
+                let ss = y - z;
+                p = (rr * ss - w) / a[mu + 1][mu] + a[mu][mu + 1];
+                q = a[mu + 1][mu + 1] - z - rr - ss;
+                r = a[mu + 2][mu + 1];
+                let s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l {
+                    break;
+                }
+                let u = a[mu][mu - 1].abs() * (q.abs() + r.abs());
+                let v = p.abs()
+                    * (a[mu - 1][mu - 1].abs() + z.abs() + a[mu + 1][mu + 1].abs());
+                if u + v == v {
+                    break;
+                }
+                m -= 1;
+            }
+
+            let mu = m as usize;
+            for i in (mu + 2)..=nu {
+                a[i][i - 2] = 0.0;
+                if i != mu + 2 {
+                    a[i][i - 3] = 0.0;
+                }
+            }
+
+            // double-shift QR sweep on rows/columns l..nn
+            let mut k = m;
+            while k < nn {
+                let ku = k as usize;
+                if k != m {
+                    p = a[ku][ku - 1];
+                    q = a[ku + 1][ku - 1];
+                    r = 0.0;
+                    if k != nn - 1 {
+                        r = a[ku + 2][ku - 1];
+                    }
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != 0.0 {
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+                }
+                let s = sign((p * p + q * q + r * r).sqrt(), p);
+                if s != 0.0 {
+                    if k == m {
+                        if l != m {
+                            a[ku][ku - 1] = -a[ku][ku - 1];
+                        }
+                    } else {
+                        a[ku][ku - 1] = -s * x;
+                    }
+                    p += s;
+                    x = p / s;
+                    y = q / s;
+                    let z = r / s;
+                    q /= p;
+                    r /= p;
+                    for j in k..=nn {
+                        let ju = j as usize;
+                        let mut pp = a[ku][ju] + q * a[ku + 1][ju];
+                        if k != nn - 1 {
+                            pp += r * a[ku + 2][ju];
+                            a[ku + 2][ju] -= pp * z;
+                        }
+                        a[ku + 1][ju] -= pp * y;
+                        a[ku][ju] -= pp * x;
+                    }
+                    let mmin = if nn < k + 3 { nn } else { k + 3 };
+                    for i in l..=mmin {
+                        let iu = i as usize;
+                        let mut pp = x * a[iu][ku] + y * a[iu][ku + 1];
+                        if k != nn - 1 {
+                            pp += z * a[iu][ku + 2];
+                            a[iu][ku + 2] -= pp * r;
+                        }
+                        a[iu][ku + 1] -= pp * q;
+                        a[iu][ku] -= pp;
+                    }
+                }
+                k += 1;
+            }
+        }
+    }
+
+    (1..=n).map(|i| Complex::new(wr[i], wi[i])).collect()
+}
+
+impl ops::Div<Polynom> for Polynom {
+    type Output = Polynom;
+
+    fn div(self, rhs: Polynom) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl ops::Rem<Polynom> for Polynom {
+    type Output = Polynom;
+
+    fn rem(self, rhs: Polynom) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,6 +803,171 @@ mod tests {
         assert_eq!(p, Polynom::initialize(vec![1.0; 11]));
     }
 
+    #[test]
+    fn div_rem_exact_division_has_no_remainder() {
+        // (X^2 + 1)(X - 1) = X^3 - X^2 + X - 1
+        let dividend = Polynom::initialize(vec![-1.0, 1.0, -1.0, 1.0]);
+        let divisor = Polynom::initialize(vec![-1.0, 1.0]);
+        let (q, r) = dividend.div_rem(&divisor);
+        assert_eq!(q, Polynom::initialize(vec![1.0, 0.0, 1.0]));
+        assert_eq!(r, Polynom::zero());
+    }
+
+    #[test]
+    fn div_rem_with_remainder() {
+        // (X^2 - 1) / (X - 2) = X + 2 remainder 3
+        let dividend = Polynom::initialize(vec![-1.0, 0.0, 1.0]);
+        let divisor = Polynom::initialize(vec![-2.0, 1.0]);
+        assert_eq!(
+            dividend.div_rem(&divisor),
+            (Polynom::initialize(vec![2.0, 1.0]), Polynom::initialize(vec![3.0]))
+        );
+    }
+
+    #[test]
+    fn div_and_rem_operators() {
+        let dividend = Polynom::initialize(vec![-1.0, 0.0, 1.0]);
+        let divisor = Polynom::initialize(vec![-2.0, 1.0]);
+        assert_eq!(
+            Polynom::initialize(vec![-1.0, 0.0, 1.0]) / Polynom::initialize(vec![-2.0, 1.0]),
+            Polynom::initialize(vec![2.0, 1.0])
+        );
+        assert_eq!(dividend % divisor, Polynom::initialize(vec![3.0]));
+    }
+
+    #[test]
+    fn gcd_extracts_common_factor() {
+        // a = (X - 1)(X + 2) = X^2 + X - 2
+        // b = (X - 1)(X - 3) = X^2 - 4X + 3
+        // gcd = X - 1 (monic)
+        let a = Polynom::initialize(vec![-2.0, 1.0, 1.0]);
+        let b = Polynom::initialize(vec![3.0, -4.0, 1.0]);
+        assert_eq!(a.gcd(b), Polynom::initialize(vec![-1.0, 1.0]));
+    }
+
+    #[test]
+    fn gcd_tolerates_floating_point_noise() {
+        // (X - 0.1)(X - 0.3) and (X - 0.1) share the monic factor X - 0.1;
+        // the exact-zero trim alone would leave sub-ulp noise in the remainder
+        // and overestimate its degree, so the tolerance is what keeps this right
+        let a = Polynom::initialize(vec![0.03, -0.4, 1.0]);
+        let b = Polynom::initialize(vec![-0.1, 1.0]);
+        assert_eq!(a.gcd(b), Polynom::initialize(vec![-0.1, 1.0]));
+    }
+
+    #[test]
+    fn gcd_of_coprime_polynomials_is_constant() {
+        let a = Polynom::initialize(vec![-1.0, 1.0]); // X - 1
+        let b = Polynom::initialize(vec![1.0, 1.0]); // X + 1
+        assert_eq!(a.gcd(b), Polynom::initialize(vec![1.0]));
+    }
+
+    #[test]
+    fn roots_of_quadratic_are_plus_minus_one() {
+        // X^2 - 1 has roots -1 and +1
+        let mut roots = Polynom::initialize(vec![-1.0, 0.0, 1.0]).roots();
+        roots.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap());
+        assert!((roots[0] - Complex::new(-1.0, 0.0)).norm() < 1e-9);
+        assert!((roots[1] - Complex::new(1.0, 0.0)).norm() < 1e-9);
+    }
+
+    #[test]
+    fn roots_converge_for_non_representable_roots() {
+        // (X - 0.1)(X - 0.3) = X^2 - 0.4X + 0.03, roots that are not exactly
+        // representable so the residual never hits exact zero
+        let mut roots = Polynom::initialize(vec![0.03, -0.4, 1.0]).roots();
+        roots.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap());
+        assert!((roots[0] - Complex::new(0.1, 0.0)).norm() < 1e-9);
+        assert!((roots[1] - Complex::new(0.3, 0.0)).norm() < 1e-9);
+    }
+
+    #[test]
+    fn roots_can_be_complex() {
+        // X^2 + 1 has roots ±i
+        let mut roots = Polynom::initialize(vec![1.0, 0.0, 1.0]).roots();
+        roots.sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+        assert!((roots[0] - Complex::new(0.0, -1.0)).norm() < 1e-9);
+        assert!((roots[1] - Complex::new(0.0, 1.0)).norm() < 1e-9);
+    }
+
+    #[test]
+    fn derivative_of_quartic() {
+        // derivative of 3X^4 - 2X - 1 is 12X^3 - 2
+        let p = Polynom::initialize(vec![-1.0, -2.0, 0.0, 0.0, 3.0]);
+        assert_eq!(format!("{}", p.derivative()), "12X^3-2");
+    }
+
+    #[test]
+    fn integral_then_derivative_is_identity() {
+        let p = Polynom::initialize(vec![-2.0, 0.0, 0.0, 12.0]); // 12X^3 - 2
+        assert_eq!(format!("{}", p.integral(-1.0)), "3X^4-2X-1");
+        assert_eq!(p.integral(-1.0).derivative(), p);
+    }
+
+    #[test]
+    fn eval_with_horner() {
+        // 3X^4 - 2X - 1 at X = 2 is 48 - 4 - 1 = 43
+        let p = Polynom::initialize(vec![-1.0, -2.0, 0.0, 0.0, 3.0]);
+        assert_eq!(p.eval(2.0), 43.0);
+    }
+
+    #[test]
+    fn companion_roots_match_real_roots() {
+        // (X - 1)(X - 2)(X - 3) = X^3 - 6X^2 + 11X - 6
+        let mut roots = Polynom::initialize(vec![-6.0, 11.0, -6.0, 1.0])
+            .find_roots(RootMethod::Companion);
+        roots.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap());
+        assert!((roots[0].re - 1.0).abs() < 1e-9 && roots[0].im.abs() < 1e-9);
+        assert!((roots[1].re - 2.0).abs() < 1e-9 && roots[1].im.abs() < 1e-9);
+        assert!((roots[2].re - 3.0).abs() < 1e-9 && roots[2].im.abs() < 1e-9);
+    }
+
+    #[test]
+    fn multiplication_agrees_across_the_fft_threshold() {
+        // degree 50 * degree 40 = 90 > FFT_THRESHOLD, so `*` takes the FFT path;
+        // it must still equal the schoolbook convolution exactly under PartialEq
+        let a: Vec<f64> = (0..=50).map(|i| ((i % 7) as f64) - 3.0).collect();
+        let b: Vec<f64> = (0..=40).map(|i| ((i % 5) as f64) - 2.0).collect();
+
+        let mut expected = vec![0.0; a.len() + b.len() - 1];
+        for (i, &ai) in a.iter().enumerate() {
+            for (j, &bj) in b.iter().enumerate() {
+                expected[i + j] += ai * bj;
+            }
+        }
+
+        let product =
+            Polynom::initialize(a.clone()) * Polynom::initialize(b.clone());
+        assert_eq!(product, Polynom::initialize(expected));
+    }
+
+    #[test]
+    fn norms_of_a_polynomial() {
+        // -4X^2 + 3X - 1
+        let p = Polynom::initialize(vec![-1.0, 3.0, -4.0]);
+        assert_eq!(p.l1_norm(), 8.0);
+        assert_eq!(p.l2_norm(), 26.0_f64.sqrt());
+        assert_eq!(p.linf_norm(), 4.0);
+    }
+
+    #[test]
+    fn monic_divides_by_leading_coefficient() {
+        // 2X^2 + 4X - 6 -> X^2 + 2X - 3
+        assert_eq!(
+            Polynom::initialize(vec![-6.0, 4.0, 2.0]).monic(),
+            Polynom::initialize(vec![-3.0, 2.0, 1.0])
+        );
+    }
+
+    #[test]
+    fn round_to_zero_drops_tiny_coefficients() {
+        let p = Polynom::initialize(vec![1e-15, 2.0, 1e-16, 3.0]);
+        assert_eq!(
+            p.round_to_zero(1e-9),
+            Polynom::initialize(vec![0.0, 2.0, 0.0, 3.0])
+        );
+    }
+
     #[test]
     fn test_format() {
         assert_eq!(format!("{}", Polynom::initialize(vec![-1.0, -2.0, 0.0, 0.0, 3.0, -4.0])), "-4X^5+3X^4-2X-1");